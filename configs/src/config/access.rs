@@ -0,0 +1,144 @@
+//! Deep, dotted-path access into a merged [`Config`] tree.
+
+use serde::de::DeserializeOwned;
+
+use super::error::ConfigError;
+use super::file;
+use super::value::Value;
+use super::Config;
+
+impl Config {
+    /// Deserializes the subtree at a dotted `path` (e.g. `"server.port"`,
+    /// `"agents[0].name"`) into `T` via serde.
+    ///
+    /// Returns [`ConfigError::Missing`] if no value exists at `path`, or
+    /// [`ConfigError::TypeMismatch`] if it exists but doesn't match `T`.
+    pub fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, ConfigError> {
+        let root = self.read();
+        let value = navigate(&root, path)?;
+        serde_json::from_value(file::to_json(value)).map_err(|err| ConfigError::TypeMismatch {
+            path: path.to_string(),
+            message: err.to_string(),
+        })
+    }
+
+    /// Shorthand for `get::<String>`.
+    pub fn get_string(&self, path: &str) -> Result<String, ConfigError> {
+        self.get(path)
+    }
+
+    /// Shorthand for `get::<i64>`.
+    pub fn get_int(&self, path: &str) -> Result<i64, ConfigError> {
+        self.get(path)
+    }
+
+    /// Shorthand for `get::<bool>`.
+    pub fn get_bool(&self, path: &str) -> Result<bool, ConfigError> {
+        self.get(path)
+    }
+}
+
+/// Walks a dotted path with optional `[index]` array indices down into
+/// `root`, e.g. `"agents[0].name"`.
+fn navigate<'a>(root: &'a Value, path: &str) -> Result<&'a Value, ConfigError> {
+    let mut current = root;
+    for segment in path.split('.') {
+        let (key, indices) = split_indices(segment, path)?;
+        if !key.is_empty() {
+            current = current
+                .as_table()
+                .and_then(|table| table.get(key))
+                .ok_or_else(|| missing(path))?;
+        }
+        for index in indices {
+            current = current
+                .as_array()
+                .and_then(|array| array.get(index))
+                .ok_or_else(|| missing(path))?;
+        }
+    }
+    Ok(current)
+}
+
+/// Splits a path segment like `"agents[0][1]"` into its table key
+/// (`"agents"`) and its array indices (`[0, 1]`). Fails on a malformed or
+/// non-numeric index rather than silently ignoring it.
+fn split_indices<'a>(segment: &'a str, path: &str) -> Result<(&'a str, Vec<usize>), ConfigError> {
+    let key_end = segment.find('[').unwrap_or(segment.len());
+    let key = &segment[..key_end];
+    let mut rest = &segment[key_end..];
+    let mut indices = Vec::new();
+    while !rest.is_empty() {
+        let close = rest.find(']').ok_or_else(|| missing(path))?;
+        let index = rest[1..close].parse::<usize>().map_err(|_| missing(path))?;
+        indices.push(index);
+        rest = &rest[close + 1..];
+    }
+    Ok((key, indices))
+}
+
+fn missing(path: &str) -> ConfigError {
+    ConfigError::Missing {
+        path: path.to_string(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn reads_scalars_and_nested_tables() {
+        let config = Config::builder()
+            .set("server.pool.max_connections", 10i64)
+            .set("server.name", "primary")
+            .build()
+            .unwrap();
+
+        assert_eq!(
+            config.get::<u16>("server.pool.max_connections").unwrap(),
+            10
+        );
+        assert_eq!(config.get_string("server.name").unwrap(), "primary");
+    }
+
+    #[test]
+    fn reads_through_array_indices() {
+        let config = Config::builder()
+            .set(
+                "agents",
+                Value::Array(vec![Value::from_path("name", Value::from("scout"))]),
+            )
+            .build()
+            .unwrap();
+
+        assert_eq!(config.get_string("agents[0].name").unwrap(), "scout");
+    }
+
+    #[test]
+    fn missing_path_is_a_descriptive_error() {
+        let config = Config::builder()
+            .set("server.port", 8080i64)
+            .build()
+            .unwrap();
+
+        let err = config.get_string("server.missing").unwrap_err();
+        match err {
+            ConfigError::Missing { path } => assert_eq!(path, "server.missing"),
+            other => panic!("expected Missing, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn malformed_index_is_an_error_not_a_silent_fallthrough() {
+        let config = Config::builder()
+            .set("agents", Value::Array(vec![Value::from("scout")]))
+            .build()
+            .unwrap();
+
+        assert!(matches!(
+            config.get_string("agents[x]"),
+            Err(ConfigError::Missing { .. })
+        ));
+    }
+}
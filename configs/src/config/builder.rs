@@ -0,0 +1,118 @@
+//! Assembling a [`Config`] from an ordered list of sources.
+
+use std::path::PathBuf;
+
+use super::env::Environment;
+use super::error::ConfigError;
+use super::file;
+use super::value::{self, Value};
+use super::Config;
+
+/// One entry in a [`ConfigBuilder`]'s source list, in the order it was
+/// added. Kept around on the built `Config` so [`Config::watch`] and
+/// [`Config::reload`] can re-run the same merge later.
+pub(crate) enum Recipe {
+    /// A single value set directly via [`ConfigBuilder::set`].
+    Overlay(Value),
+    /// A TOML/JSON/YAML file, (re-)parsed on every build.
+    File(PathBuf),
+    /// Environment variables matching a prefix, via [`ConfigBuilder::environment`].
+    Environment(Environment),
+    /// An already-built `Config` to merge in wholesale.
+    Config(Config),
+}
+
+impl Recipe {
+    /// Resolves this entry to the `Value` it currently contributes.
+    pub(crate) fn resolve(&self) -> Result<Value, ConfigError> {
+        match self {
+            Recipe::Overlay(value) => Ok(value.clone()),
+            Recipe::File(path) => file::load(path),
+            Recipe::Environment(environment) => Ok(environment.load()),
+            Recipe::Config(config) => config.rebuild(),
+        }
+    }
+
+    /// Collects the paths of any file sources reachable from this entry,
+    /// recursing into nested `Config`s.
+    pub(crate) fn collect_file_paths(&self, paths: &mut Vec<PathBuf>) {
+        match self {
+            Recipe::File(path) => paths.push(path.clone()),
+            Recipe::Config(config) => paths.extend(config.file_paths()),
+            Recipe::Overlay(_) | Recipe::Environment(_) => {}
+        }
+    }
+}
+
+/// Builds a [`Config`] by folding an ordered list of sources together.
+///
+/// Sources are applied left to right; later sources take precedence over
+/// earlier ones on conflicting keys. See [`value::merge`] for exactly how
+/// two sources are combined.
+///
+/// ```no_run
+/// # use configs::config::Config;
+/// let config = Config::builder()
+///     .set("server.port", 3000i64)
+///     .file("config/default.toml")
+///     .file("config/local.toml")
+///     .environment("AIAGENCY")
+///     .build()?;
+/// # Ok::<(), configs::config::ConfigError>(())
+/// ```
+#[derive(Default)]
+pub struct ConfigBuilder {
+    sources: Vec<Recipe>,
+}
+
+impl ConfigBuilder {
+    /// Creates an empty builder with no sources.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets a single default value at a dotted path, e.g. `"server.port"`.
+    pub fn set(mut self, path: &str, value: impl Into<Value>) -> Self {
+        self.sources
+            .push(Recipe::Overlay(Value::from_path(path, value.into())));
+        self
+    }
+
+    /// Merges in a file, choosing its parser (TOML, JSON, or YAML) from the
+    /// file's extension.
+    pub fn file(mut self, path: impl Into<PathBuf>) -> Self {
+        self.sources.push(Recipe::File(path.into()));
+        self
+    }
+
+    /// Merges in environment variables matching `prefix` (see
+    /// [`Environment`] for the naming convention). Typically added last so
+    /// runtime overrides win over file and default sources.
+    pub fn environment(mut self, prefix: impl Into<String>) -> Self {
+        self.sources
+            .push(Recipe::Environment(Environment::with_prefix(prefix)));
+        self
+    }
+
+    /// Merges in an already-built `Config`.
+    pub fn config(mut self, config: Config) -> Self {
+        self.sources.push(Recipe::Config(config));
+        self
+    }
+
+    /// Folds all sources left-to-right into a single merged `Config`.
+    pub fn build(self) -> Result<Config, ConfigError> {
+        let root = rebuild(&self.sources)?;
+        Ok(Config::new(root, self.sources))
+    }
+}
+
+/// Folds a recipe list left-to-right into a single merged `Value`. Shared
+/// by the initial [`ConfigBuilder::build`] and by [`Config::reload`].
+pub(crate) fn rebuild(sources: &[Recipe]) -> Result<Value, ConfigError> {
+    let mut root = Value::table();
+    for source in sources {
+        root = value::merge(root, source.resolve()?);
+    }
+    Ok(root)
+}
@@ -0,0 +1,101 @@
+//! Auto-detecting a project's config file by walking up from the cwd.
+
+use std::env;
+use std::path::{Path, PathBuf};
+
+use super::error::ConfigError;
+
+/// Config file names recognized by [`discover`], checked in this order
+/// within each candidate directory.
+const RECOGNIZED_FILES: &[&str] = &["aiagency.toml", ".aiagency/config.toml"];
+
+/// How the config file to load is specified.
+pub enum ConfigPath {
+    /// Load the file at this exact path.
+    Explicit(PathBuf),
+    /// Walk up from the current directory looking for a recognized config
+    /// file, similar to how a toolchain locates its source by probing the
+    /// sysroot.
+    Discover,
+}
+
+/// The result of a successful [`discover`] call.
+pub struct Discovered {
+    /// The resolved config file.
+    pub config_file: PathBuf,
+    /// The directory it was found in, i.e. the detected project root.
+    pub project_root: PathBuf,
+}
+
+/// Walks upward from `start` (and its ancestors) looking for the first
+/// directory containing one of [`RECOGNIZED_FILES`].
+pub fn discover(start: &Path) -> Result<Discovered, ConfigError> {
+    for dir in start.ancestors() {
+        for name in RECOGNIZED_FILES {
+            let candidate = dir.join(name);
+            if candidate.is_file() {
+                return Ok(Discovered {
+                    config_file: candidate,
+                    project_root: dir.to_path_buf(),
+                });
+            }
+        }
+    }
+    Err(ConfigError::NotDiscovered {
+        start: start.to_path_buf(),
+    })
+}
+
+/// Resolves a [`ConfigPath`] to a concrete file, discovering it from the
+/// current working directory when `path` is [`ConfigPath::Discover`].
+pub fn resolve_config_path(path: ConfigPath) -> Result<Discovered, ConfigError> {
+    match path {
+        ConfigPath::Explicit(path) => {
+            let project_root = path
+                .parent()
+                .map(Path::to_path_buf)
+                .unwrap_or_else(|| PathBuf::from("."));
+            Ok(Discovered {
+                config_file: path,
+                project_root,
+            })
+        }
+        ConfigPath::Discover => {
+            let cwd = env::current_dir().map_err(ConfigError::Io)?;
+            discover(&cwd)
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    #[test]
+    fn finds_config_file_in_parent_directory() {
+        let root = env::temp_dir().join(format!("configs-discover-{}", std::process::id()));
+        let nested = root.join("a").join("b");
+        fs::create_dir_all(&nested).unwrap();
+        fs::write(root.join("aiagency.toml"), "").unwrap();
+
+        let found = discover(&nested).unwrap();
+        assert_eq!(found.project_root, root);
+        assert_eq!(found.config_file, root.join("aiagency.toml"));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+
+    #[test]
+    fn errors_when_nothing_found() {
+        let root = env::temp_dir().join(format!("configs-discover-empty-{}", std::process::id()));
+        fs::create_dir_all(&root).unwrap();
+
+        assert!(matches!(
+            discover(&root),
+            Err(ConfigError::NotDiscovered { .. })
+        ));
+
+        let _ = fs::remove_dir_all(&root);
+    }
+}
@@ -0,0 +1,123 @@
+//! Overriding config values from the process environment.
+
+use std::env;
+
+use super::value::Value;
+
+/// A source that reads config overrides out of environment variables,
+/// following the convention used by tools like mdBook: a prefixed,
+/// separator-delimited variable name maps onto a dotted config path.
+///
+/// `AIAGENCY_SERVER__PORT=8080` with prefix `AIAGENCY` and the default
+/// `__` separator maps to `server.port = 8080`.
+#[derive(Clone)]
+pub struct Environment {
+    prefix: String,
+    separator: String,
+    coerce: bool,
+}
+
+impl Environment {
+    /// Creates an `Environment` source for the given prefix, using the
+    /// default `__` separator and numeric/boolean coercion enabled.
+    pub fn with_prefix(prefix: impl Into<String>) -> Self {
+        Environment {
+            prefix: prefix.into(),
+            separator: "__".to_string(),
+            coerce: true,
+        }
+    }
+
+    /// Overrides the separator used to split a variable name into a
+    /// nested config path. Defaults to `__`.
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// Disables type coercion, so every value is kept as a `Value::String`
+    /// instead of being parsed into bools/integers/floats.
+    pub fn keep_strings(mut self) -> Self {
+        self.coerce = false;
+        self
+    }
+
+    /// Reads matching environment variables and builds the overlay tree
+    /// they describe.
+    pub(crate) fn load(&self) -> Value {
+        let var_prefix = format!("{}_", self.prefix.to_uppercase());
+        let mut root = Value::table();
+        for (name, raw) in env::vars() {
+            let Some(rest) = name.strip_prefix(&var_prefix) else {
+                continue;
+            };
+            if rest.is_empty() {
+                continue;
+            }
+            let path = rest
+                .split(self.separator.as_str())
+                .map(|segment| segment.to_lowercase())
+                .collect::<Vec<_>>()
+                .join(".");
+            let value = if self.coerce {
+                coerce(&raw)
+            } else {
+                Value::String(raw)
+            };
+            root = super::value::merge(root, Value::from_path(&path, value));
+        }
+        root
+    }
+}
+
+/// Parses a raw environment variable string into a bool, integer, or
+/// float when it unambiguously looks like one; otherwise keeps it as a
+/// string.
+fn coerce(raw: &str) -> Value {
+    match raw {
+        "true" => return Value::Bool(true),
+        "false" => return Value::Bool(false),
+        _ => {}
+    }
+    if let Ok(i) = raw.parse::<i64>() {
+        return Value::Integer(i);
+    }
+    if let Ok(f) = raw.parse::<f64>() {
+        return Value::Float(f);
+    }
+    Value::String(raw.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn with_var<F: FnOnce()>(name: &str, value: &str, f: F) {
+        env::set_var(name, value);
+        f();
+        env::remove_var(name);
+    }
+
+    #[test]
+    fn nested_key_is_split_and_lowercased() {
+        with_var("ENVTEST_SERVER__PORT", "8080", || {
+            let root = Environment::with_prefix("envtest").load();
+            let server = root.as_table().unwrap().get("server").unwrap();
+            assert_eq!(
+                server.as_table().unwrap().get("port"),
+                Some(&Value::Integer(8080))
+            );
+        });
+    }
+
+    #[test]
+    fn coercion_can_be_disabled() {
+        with_var("ENVTESTSTR_FLAG", "true", || {
+            let root = Environment::with_prefix("envteststr").keep_strings().load();
+            assert_eq!(
+                root.as_table().unwrap().get("flag"),
+                Some(&Value::String("true".to_string()))
+            );
+        });
+    }
+}
@@ -0,0 +1,68 @@
+//! Error type for configuration loading and merging.
+
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+/// Errors produced while loading or merging configuration.
+#[derive(Debug)]
+pub enum ConfigError {
+    /// An I/O operation failed: a file source could not be read, or the
+    /// current directory could not be determined for discovery.
+    Io(std::io::Error),
+    /// A file source was read but failed to parse.
+    Parse { path: PathBuf, message: String },
+    /// A file source's extension isn't one of the supported formats.
+    UnsupportedFormat(String),
+    /// [`ConfigPath::Discover`](super::ConfigPath::Discover) walked up from
+    /// the starting directory without finding a recognized config file.
+    NotDiscovered { start: PathBuf },
+    /// Setting up or running a filesystem watcher for [`Config::watch`](super::Config::watch) failed.
+    Watch(notify::Error),
+    /// [`Config::get`](super::Config::get) was asked for a path that doesn't
+    /// exist in the merged tree.
+    Missing { path: String },
+    /// [`Config::get`](super::Config::get) found the path, but its value
+    /// couldn't be deserialized into the requested type.
+    TypeMismatch { path: String, message: String },
+}
+
+impl ConfigError {
+    pub(crate) fn parse(path: &Path, message: String) -> Self {
+        ConfigError::Parse {
+            path: path.to_path_buf(),
+            message,
+        }
+    }
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::Io(err) => write!(f, "config I/O error: {err}"),
+            ConfigError::Parse { path, message } => {
+                write!(f, "failed to parse {}: {}", path.display(), message)
+            }
+            ConfigError::UnsupportedFormat(ext) => {
+                write!(f, "unsupported config file format: .{ext}")
+            }
+            ConfigError::NotDiscovered { start } => write!(
+                f,
+                "no recognized config file found in {} or any parent directory",
+                start.display()
+            ),
+            ConfigError::Watch(err) => write!(f, "failed to watch config file: {err}"),
+            ConfigError::Missing { path } => write!(f, "no value found at config path `{path}`"),
+            ConfigError::TypeMismatch { path, message } => {
+                write!(f, "config path `{path}` has the wrong type: {message}")
+            }
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+impl From<std::io::Error> for ConfigError {
+    fn from(err: std::io::Error) -> Self {
+        ConfigError::Io(err)
+    }
+}
@@ -0,0 +1,104 @@
+//! Parsing of file-backed config sources, selected by extension.
+
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+
+use super::error::ConfigError;
+use super::value::Value;
+
+/// Reads and parses a config file, choosing the format (TOML, JSON, or
+/// YAML) from the file's extension.
+pub fn load(path: &Path) -> Result<Value, ConfigError> {
+    let contents = fs::read_to_string(path)?;
+    parse(path, &contents)
+}
+
+fn parse(path: &Path, contents: &str) -> Result<Value, ConfigError> {
+    match path.extension().and_then(|ext| ext.to_str()) {
+        Some("toml") => {
+            let parsed: toml::Value = toml::from_str(contents)
+                .map_err(|err| ConfigError::parse(path, err.to_string()))?;
+            Ok(from_toml(parsed))
+        }
+        Some("json") => {
+            let parsed: serde_json::Value = serde_json::from_str(contents)
+                .map_err(|err| ConfigError::parse(path, err.to_string()))?;
+            Ok(from_json(parsed))
+        }
+        Some("yaml") | Some("yml") => {
+            let parsed: serde_yaml::Value = serde_yaml::from_str(contents)
+                .map_err(|err| ConfigError::parse(path, err.to_string()))?;
+            Ok(from_yaml(parsed))
+        }
+        Some(other) => Err(ConfigError::UnsupportedFormat(other.to_string())),
+        None => Err(ConfigError::UnsupportedFormat(String::new())),
+    }
+}
+
+fn from_toml(value: toml::Value) -> Value {
+    match value {
+        toml::Value::String(s) => Value::String(s),
+        toml::Value::Integer(i) => Value::Integer(i),
+        toml::Value::Float(f) => Value::Float(f),
+        toml::Value::Boolean(b) => Value::Bool(b),
+        toml::Value::Datetime(dt) => Value::String(dt.to_string()),
+        toml::Value::Array(arr) => Value::Array(arr.into_iter().map(from_toml).collect()),
+        toml::Value::Table(table) => {
+            Value::Table(table.into_iter().map(|(k, v)| (k, from_toml(v))).collect())
+        }
+    }
+}
+
+fn from_json(value: serde_json::Value) -> Value {
+    match value {
+        serde_json::Value::Null => Value::Null,
+        serde_json::Value::Bool(b) => Value::Bool(b),
+        serde_json::Value::Number(n) => match n.as_i64() {
+            Some(i) => Value::Integer(i),
+            None => Value::Float(n.as_f64().unwrap_or_default()),
+        },
+        serde_json::Value::String(s) => Value::String(s),
+        serde_json::Value::Array(arr) => Value::Array(arr.into_iter().map(from_json).collect()),
+        serde_json::Value::Object(map) => {
+            Value::Table(map.into_iter().map(|(k, v)| (k, from_json(v))).collect())
+        }
+    }
+}
+
+/// Converts a `Value` to `serde_json::Value`, used to deserialize a
+/// subtree into an arbitrary caller type via serde.
+pub(crate) fn to_json(value: &Value) -> serde_json::Value {
+    match value {
+        Value::Null => serde_json::Value::Null,
+        Value::Bool(b) => serde_json::Value::Bool(*b),
+        Value::Integer(i) => serde_json::Value::Number((*i).into()),
+        Value::Float(f) => serde_json::Number::from_f64(*f)
+            .map(serde_json::Value::Number)
+            .unwrap_or(serde_json::Value::Null),
+        Value::String(s) => serde_json::Value::String(s.clone()),
+        Value::Array(arr) => serde_json::Value::Array(arr.iter().map(to_json).collect()),
+        Value::Table(table) => {
+            serde_json::Value::Object(table.iter().map(|(k, v)| (k.clone(), to_json(v))).collect())
+        }
+    }
+}
+
+fn from_yaml(value: serde_yaml::Value) -> Value {
+    match value {
+        serde_yaml::Value::Null => Value::Null,
+        serde_yaml::Value::Bool(b) => Value::Bool(b),
+        serde_yaml::Value::Number(n) => match n.as_i64() {
+            Some(i) => Value::Integer(i),
+            None => Value::Float(n.as_f64().unwrap_or_default()),
+        },
+        serde_yaml::Value::String(s) => Value::String(s),
+        serde_yaml::Value::Sequence(seq) => Value::Array(seq.into_iter().map(from_yaml).collect()),
+        serde_yaml::Value::Mapping(map) => Value::Table(
+            map.into_iter()
+                .filter_map(|(k, v)| k.as_str().map(|k| (k.to_string(), from_yaml(v))))
+                .collect::<BTreeMap<_, _>>(),
+        ),
+        serde_yaml::Value::Tagged(tagged) => from_yaml(tagged.value),
+    }
+}
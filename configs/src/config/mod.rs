@@ -0,0 +1,155 @@
+//! Layered configuration loading.
+//!
+//! A [`Config`] is a merged tree of [`Value`]s built by
+//! [`ConfigBuilder`] from an ordered list of sources: in-memory defaults,
+//! files, environment variables, or other `Config`s. Later sources take
+//! precedence over earlier ones; see [`value::merge`] for the merge rule.
+//!
+//! A `Config` built from file sources can also [`watch`](Config::watch)
+//! those files and reload itself when they change, which is useful for
+//! long-running processes that want to pick up edits without restarting.
+
+mod access;
+mod builder;
+mod discover;
+mod env;
+mod error;
+mod file;
+mod paths;
+mod value;
+mod watch;
+
+use std::fmt;
+use std::path::PathBuf;
+use std::sync::{Arc, Mutex, RwLock};
+
+use builder::Recipe;
+
+pub use builder::ConfigBuilder;
+pub use discover::{resolve_config_path, ConfigPath, Discovered};
+pub use env::Environment;
+pub use error::ConfigError;
+pub use paths::{config_dir, find_config_file, resolve_config_dir};
+pub use value::Value;
+pub use watch::Watch;
+
+/// Returns the default configuration path.
+#[deprecated(
+    since = "0.2.0",
+    note = "use `resolve_config_dir` for a portable, per-OS path"
+)]
+pub fn default_path() -> &'static str {
+    ".config"
+}
+
+type ReloadCallback = Box<dyn FnMut(&Config) + Send>;
+
+/// A merged configuration tree produced by [`ConfigBuilder::build`].
+///
+/// Cloning a `Config` is cheap and shares state with the original: a
+/// [`reload`](Config::reload) on one clone is visible through all of them.
+#[derive(Clone)]
+pub struct Config {
+    root: Arc<RwLock<Value>>,
+    sources: Arc<Vec<Recipe>>,
+    on_reload: Arc<Mutex<Vec<ReloadCallback>>>,
+}
+
+impl Config {
+    /// Starts a new builder for assembling a layered `Config`.
+    pub fn builder() -> ConfigBuilder {
+        ConfigBuilder::new()
+    }
+
+    pub(crate) fn new(root: Value, sources: Vec<Recipe>) -> Self {
+        Config {
+            root: Arc::new(RwLock::new(root)),
+            sources: Arc::new(sources),
+            on_reload: Arc::new(Mutex::new(Vec::new())),
+        }
+    }
+
+    /// Returns the current merged value tree.
+    pub(crate) fn snapshot(&self) -> Value {
+        self.root.read().unwrap().clone()
+    }
+
+    /// Borrows the current merged value tree without cloning it.
+    pub(crate) fn read(&self) -> std::sync::RwLockReadGuard<'_, Value> {
+        self.root.read().unwrap()
+    }
+
+    /// Re-runs this config's sources (re-reading any files) and replaces
+    /// the current value tree, then notifies any [`on_reload`](Self::on_reload)
+    /// callbacks. Used by [`watch`](Self::watch); can also be called
+    /// directly to force a one-off reload.
+    pub fn reload(&self) -> Result<(), ConfigError> {
+        let next = self.rebuild()?;
+        *self.root.write().unwrap() = next;
+        for callback in self.on_reload.lock().unwrap().iter_mut() {
+            callback(self);
+        }
+        Ok(())
+    }
+
+    /// Registers a callback invoked after every successful [`reload`](Self::reload).
+    pub fn on_reload<F>(&self, callback: F)
+    where
+        F: FnMut(&Config) + Send + 'static,
+    {
+        self.on_reload.lock().unwrap().push(Box::new(callback));
+    }
+
+    /// Starts watching this config's file-backed sources for changes,
+    /// reloading automatically when they're modified. See [`Watch`].
+    pub fn watch(&self) -> Result<Watch, ConfigError> {
+        watch::start(self.clone())
+    }
+
+    pub(crate) fn rebuild(&self) -> Result<Value, ConfigError> {
+        builder::rebuild(&self.sources)
+    }
+
+    pub(crate) fn file_paths(&self) -> Vec<PathBuf> {
+        let mut paths = Vec::new();
+        for source in self.sources.iter() {
+            source.collect_file_paths(&mut paths);
+        }
+        paths
+    }
+}
+
+impl fmt::Debug for Config {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Config")
+            .field("root", &self.snapshot())
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    #[allow(deprecated)]
+    fn test_default_path() {
+        assert_eq!(default_path(), ".config");
+    }
+
+    #[test]
+    fn later_sources_override_earlier_ones() {
+        let config = Config::builder()
+            .set("server.port", 3000i64)
+            .set("server.port", 8080i64)
+            .set("server.host", "localhost")
+            .build()
+            .unwrap();
+
+        let root = config.snapshot();
+        let server = root.as_table().unwrap().get("server").unwrap();
+        let server = server.as_table().unwrap();
+        assert_eq!(server.get("port"), Some(&Value::Integer(8080)));
+        assert_eq!(server.get("host"), Some(&Value::from("localhost")));
+    }
+}
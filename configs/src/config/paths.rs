@@ -0,0 +1,134 @@
+//! Cross-platform resolution of per-user configuration directories.
+
+use std::env;
+use std::fs;
+use std::io;
+use std::path::{Path, PathBuf};
+
+/// Returns the per-user configuration directory for `app_name` on the
+/// current OS, without creating it:
+///
+/// - Linux: `$XDG_CONFIG_HOME/<app>`, falling back to `~/.config/<app>`
+/// - macOS: `~/Library/Application Support/<app>`
+/// - Windows: `%APPDATA%\<app>`
+pub fn config_dir(app_name: &str) -> io::Result<PathBuf> {
+    base_config_dir()
+        .map(|dir| dir.join(app_name))
+        .ok_or_else(|| {
+            io::Error::new(
+                io::ErrorKind::NotFound,
+                "could not determine home directory",
+            )
+        })
+}
+
+/// Like [`config_dir`], but creates the directory (and any missing
+/// parents) if it doesn't already exist.
+pub fn resolve_config_dir(app_name: &str) -> io::Result<PathBuf> {
+    let dir = config_dir(app_name)?;
+    fs::create_dir_all(&dir)?;
+    Ok(dir)
+}
+
+#[cfg(target_os = "linux")]
+fn base_config_dir() -> Option<PathBuf> {
+    if let Some(xdg) = env::var_os("XDG_CONFIG_HOME").filter(|v| !v.is_empty()) {
+        return Some(PathBuf::from(xdg));
+    }
+    home_dir().map(|home| home.join(".config"))
+}
+
+#[cfg(target_os = "macos")]
+fn base_config_dir() -> Option<PathBuf> {
+    home_dir().map(|home| home.join("Library").join("Application Support"))
+}
+
+#[cfg(target_os = "windows")]
+fn base_config_dir() -> Option<PathBuf> {
+    env::var_os("APPDATA").map(PathBuf::from)
+}
+
+#[cfg(not(any(target_os = "linux", target_os = "macos", target_os = "windows")))]
+fn base_config_dir() -> Option<PathBuf> {
+    home_dir().map(|home| home.join(".config"))
+}
+
+#[cfg(not(target_os = "windows"))]
+fn home_dir() -> Option<PathBuf> {
+    env::var_os("HOME").map(PathBuf::from)
+}
+
+/// Searches, in order of precedence, for `filename` belonging to
+/// `app_name` and returns the first path that exists:
+///
+/// 1. `<APP_NAME>_CONFIG` environment variable (explicit override, used
+///    as-is regardless of `filename`)
+/// 2. `./<filename>` in the current directory
+/// 3. `<resolved per-user config dir>/<filename>`
+///
+/// Returns `None` if none of these exist.
+pub fn find_config_file(app_name: &str, filename: &str) -> Option<PathBuf> {
+    if let Some(path) = env::var_os(env_override_var(app_name)).map(PathBuf::from) {
+        if path.exists() {
+            return Some(path);
+        }
+    }
+
+    let cwd_candidate = Path::new(filename);
+    if cwd_candidate.exists() {
+        return Some(cwd_candidate.to_path_buf());
+    }
+
+    let user_candidate = config_dir(app_name).ok()?.join(filename);
+    user_candidate.exists().then_some(user_candidate)
+}
+
+fn env_override_var(app_name: &str) -> String {
+    format!("{}_CONFIG", app_name.to_uppercase())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env_override_var_is_uppercased_with_suffix() {
+        assert_eq!(env_override_var("aiagency"), "AIAGENCY_CONFIG");
+    }
+
+    #[test]
+    fn find_config_file_prefers_explicit_env_override() {
+        let dir = env::temp_dir().join(format!("configs-test-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let explicit = dir.join("explicit.toml");
+        fs::write(&explicit, "").unwrap();
+
+        env::set_var("PATHSTEST_CONFIG", &explicit);
+        let found = find_config_file("pathstest", "unused.toml");
+        env::remove_var("PATHSTEST_CONFIG");
+
+        assert_eq!(found, Some(explicit));
+        let _ = fs::remove_dir_all(&dir);
+    }
+
+    #[test]
+    fn find_config_file_does_not_create_the_user_config_dir() {
+        let xdg_home = env::temp_dir().join(format!("configs-xdg-{}", std::process::id()));
+        let _ = fs::remove_dir_all(&xdg_home);
+        let prev = env::var_os("XDG_CONFIG_HOME");
+        env::set_var("XDG_CONFIG_HOME", &xdg_home);
+
+        let found = find_config_file("probeapp", "nonexistent.toml");
+
+        match prev {
+            Some(value) => env::set_var("XDG_CONFIG_HOME", value),
+            None => env::remove_var("XDG_CONFIG_HOME"),
+        }
+
+        assert_eq!(found, None);
+        assert!(
+            !xdg_home.exists(),
+            "probing for a config file must not create the config dir as a side effect"
+        );
+    }
+}
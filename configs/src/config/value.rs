@@ -0,0 +1,147 @@
+//! The value tree that merged configuration is stored as.
+
+use std::collections::BTreeMap;
+
+/// A single node in a configuration tree.
+///
+/// File sources (TOML/JSON/YAML) and in-memory defaults are all converted
+/// into this common representation so they can be merged uniformly.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Value {
+    Null,
+    Bool(bool),
+    Integer(i64),
+    Float(f64),
+    String(String),
+    Array(Vec<Value>),
+    Table(BTreeMap<String, Value>),
+}
+
+impl Value {
+    /// Returns an empty table.
+    pub fn table() -> Value {
+        Value::Table(BTreeMap::new())
+    }
+
+    /// Builds the nested table that results from assigning `value` at a
+    /// dotted `path`, e.g. `"server.port"` becomes `{server: {port: value}}`.
+    pub(crate) fn from_path(path: &str, value: Value) -> Value {
+        let mut node = value;
+        for segment in path.rsplit('.') {
+            let mut table = BTreeMap::new();
+            table.insert(segment.to_string(), node);
+            node = Value::Table(table);
+        }
+        node
+    }
+
+    pub fn as_table(&self) -> Option<&BTreeMap<String, Value>> {
+        match self {
+            Value::Table(table) => Some(table),
+            _ => None,
+        }
+    }
+
+    pub fn as_array(&self) -> Option<&Vec<Value>> {
+        match self {
+            Value::Array(array) => Some(array),
+            _ => None,
+        }
+    }
+}
+
+impl From<bool> for Value {
+    fn from(value: bool) -> Self {
+        Value::Bool(value)
+    }
+}
+
+impl From<i64> for Value {
+    fn from(value: i64) -> Self {
+        Value::Integer(value)
+    }
+}
+
+impl From<f64> for Value {
+    fn from(value: f64) -> Self {
+        Value::Float(value)
+    }
+}
+
+impl From<&str> for Value {
+    fn from(value: &str) -> Self {
+        Value::String(value.to_string())
+    }
+}
+
+impl From<String> for Value {
+    fn from(value: String) -> Self {
+        Value::String(value)
+    }
+}
+
+/// Deep-merges `overlay` into `base`, with `overlay` taking precedence.
+///
+/// When both sides have a table at the same key, the merge recurses into
+/// it; otherwise (scalars, arrays, or a type mismatch) the overlay value
+/// replaces the base value wholesale.
+pub fn merge(base: Value, overlay: Value) -> Value {
+    match (base, overlay) {
+        (Value::Table(mut base_table), Value::Table(overlay_table)) => {
+            for (key, overlay_value) in overlay_table {
+                let merged = match base_table.remove(&key) {
+                    Some(base_value) => merge(base_value, overlay_value),
+                    None => overlay_value,
+                };
+                base_table.insert(key, merged);
+            }
+            Value::Table(base_table)
+        }
+        (_, overlay) => overlay,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn scalars_are_replaced_wholesale() {
+        assert_eq!(
+            merge(Value::Integer(1), Value::Integer(2)),
+            Value::Integer(2)
+        );
+    }
+
+    #[test]
+    fn tables_merge_recursively() {
+        let base = Value::from_path("server.host", Value::from("localhost"));
+        let overlay = Value::from_path("server.port", Value::from(8080i64));
+        let merged = merge(base, overlay);
+
+        let server = merged
+            .as_table()
+            .unwrap()
+            .get("server")
+            .unwrap()
+            .as_table()
+            .unwrap();
+        assert_eq!(server.get("host"), Some(&Value::from("localhost")));
+        assert_eq!(server.get("port"), Some(&Value::Integer(8080)));
+    }
+
+    #[test]
+    fn overlay_table_key_replaces_base_scalar() {
+        let base = Value::from_path("server", Value::from("disabled"));
+        let overlay = Value::from_path("server.port", Value::from(8080i64));
+        let merged = merge(base, overlay);
+
+        assert!(merged
+            .as_table()
+            .unwrap()
+            .get("server")
+            .unwrap()
+            .as_table()
+            .is_some());
+    }
+}
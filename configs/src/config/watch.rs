@@ -0,0 +1,152 @@
+//! Filesystem watching and debounced reload notifications for a [`Config`].
+
+use std::path::Path;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::{mpsc, Arc};
+use std::thread;
+use std::time::Duration;
+
+use notify::{Event, EventKind, RecommendedWatcher, RecursiveMode, Watcher as _};
+
+use super::error::ConfigError;
+use super::Config;
+
+/// How long to wait after the last filesystem event before reloading, so a
+/// burst of editor writes (e.g. a save-as-replace) produces a single
+/// reload instead of several.
+const DEBOUNCE: Duration = Duration::from_millis(200);
+
+/// A handle returned by [`Config::watch`]. Keeps the filesystem watcher
+/// alive; dropping it stops watching and reloading.
+pub struct Watch {
+    _watcher: RecommendedWatcher,
+    changes: mpsc::Receiver<Result<(), ConfigError>>,
+    stop: Arc<AtomicBool>,
+}
+
+impl Watch {
+    /// A channel that receives the outcome of every reload attempt (`Ok`
+    /// on success, the `ConfigError` a failed reload hit, e.g. invalid
+    /// YAML from a save-in-progress), for callers that prefer polling over
+    /// registering an [`on_reload`](Config::on_reload) callback.
+    pub fn changes(&self) -> &mpsc::Receiver<Result<(), ConfigError>> {
+        &self.changes
+    }
+}
+
+impl Drop for Watch {
+    fn drop(&mut self) {
+        self.stop.store(true, Ordering::SeqCst);
+    }
+}
+
+/// Spawns a watcher over `config`'s file-backed sources and a debounce
+/// thread that calls [`Config::reload`] once per quiet period.
+pub(crate) fn start(config: Config) -> Result<Watch, ConfigError> {
+    let paths = config.file_paths();
+    let (raw_tx, raw_rx) = mpsc::channel::<()>();
+
+    let mut watcher = notify::recommended_watcher(move |res: notify::Result<Event>| {
+        if let Ok(event) = res {
+            if matches!(
+                event.kind,
+                EventKind::Modify(_) | EventKind::Create(_) | EventKind::Remove(_)
+            ) {
+                let _ = raw_tx.send(());
+            }
+        }
+    })
+    .map_err(ConfigError::Watch)?;
+
+    // Watch each file's parent directory rather than the file itself:
+    // many editors save by writing a new file and renaming it over the
+    // original, which a direct file watch can miss.
+    for path in &paths {
+        let dir = path.parent().unwrap_or_else(|| Path::new("."));
+        watcher
+            .watch(dir, RecursiveMode::NonRecursive)
+            .map_err(ConfigError::Watch)?;
+    }
+
+    let (change_tx, change_rx) = mpsc::channel::<Result<(), ConfigError>>();
+    let stop = Arc::new(AtomicBool::new(false));
+    let thread_stop = Arc::clone(&stop);
+
+    thread::spawn(move || loop {
+        if thread_stop.load(Ordering::SeqCst) {
+            return;
+        }
+        if raw_rx.recv_timeout(Duration::from_millis(500)).is_err() {
+            continue;
+        }
+        loop {
+            if thread_stop.load(Ordering::SeqCst) {
+                return;
+            }
+            match raw_rx.recv_timeout(DEBOUNCE) {
+                Ok(()) => continue,
+                Err(mpsc::RecvTimeoutError::Timeout) => break,
+                Err(mpsc::RecvTimeoutError::Disconnected) => return,
+            }
+        }
+        let _ = change_tx.send(config.reload());
+    });
+
+    Ok(Watch {
+        _watcher: watcher,
+        changes: change_rx,
+        stop,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::fs;
+    use std::time::Duration;
+
+    use super::Config;
+
+    fn temp_config_file(label: &str, contents: &str) -> std::path::PathBuf {
+        let dir =
+            std::env::temp_dir().join(format!("configs-watch-{}-{}", std::process::id(), label));
+        fs::create_dir_all(&dir).unwrap();
+        let path = dir.join("config.toml");
+        fs::write(&path, contents).unwrap();
+        path
+    }
+
+    #[test]
+    fn watch_reloads_and_notifies_on_file_change() {
+        let path = temp_config_file("reload", "value = 1\n");
+        let config = Config::builder().file(path.clone()).build().unwrap();
+        let watch = config.watch().unwrap();
+
+        fs::write(&path, "value = 2\n").unwrap();
+
+        let result = watch
+            .changes()
+            .recv_timeout(Duration::from_secs(5))
+            .expect("expected a reload notification within 5s");
+        assert!(result.is_ok());
+        assert_eq!(config.get_int("value").unwrap(), 2);
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+
+    #[test]
+    fn watch_surfaces_reload_errors_instead_of_swallowing_them() {
+        let path = temp_config_file("reload-error", "value = 1\n");
+        let config = Config::builder().file(path.clone()).build().unwrap();
+        let watch = config.watch().unwrap();
+
+        fs::write(&path, "not valid toml [[[").unwrap();
+
+        let result = watch
+            .changes()
+            .recv_timeout(Duration::from_secs(5))
+            .expect("expected a reload notification within 5s");
+        assert!(result.is_err());
+
+        let _ = fs::remove_dir_all(path.parent().unwrap());
+    }
+}